@@ -0,0 +1,44 @@
+//! [`Instrument`] implementation which fans a single span out to several
+//! instruments of the same type.
+
+use crate::Instrument;
+
+/// [`Instrument`] implementation which holds a fixed-size list of
+/// instruments and forwards `on_enter`/`on_exit` to each of them.
+///
+/// This lets a single span drive several signals at once (eg. several GPIO
+/// pins) without manually nesting `trace_*` calls. `on_exit` runs in reverse
+/// order of `on_enter`, keeping the nesting symmetric. For fanning out to
+/// instruments of *different* types, [`Instrument`] is implemented directly
+/// on tuples instead.
+pub struct Chain<I, const N: usize> {
+    instruments: [I; N],
+}
+
+impl<I, const N: usize> Chain<I, N> {
+    /// Create a new [`Chain`] over the given instruments.
+    #[inline]
+    pub fn new(instruments: [I; N]) -> Self {
+        Self { instruments }
+    }
+
+    /// Return the underlying instruments.
+    #[inline]
+    pub fn free(self) -> [I; N] {
+        self.instruments
+    }
+}
+
+impl<I: Instrument, const N: usize> Instrument for Chain<I, N> {
+    fn on_enter(&mut self) {
+        for instrument in self.instruments.iter_mut() {
+            instrument.on_enter();
+        }
+    }
+
+    fn on_exit(&mut self) {
+        for instrument in self.instruments.iter_mut().rev() {
+            instrument.on_exit();
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! [`Instrument`] implementation which counts how many times a span is
+//! entered.
+
+use crate::Instrument;
+
+/// [`Instrument`] implementation which counts every `on_enter`.
+///
+/// Pairing this with [`trace_poll`](crate::TraceFuture::trace_poll) counts
+/// how many times a future is actually polled, surfacing the common
+/// embedded async bug where a misbehaving waker causes busy-polling.
+/// Pairing it with [`trace_task`](crate::TraceFuture::trace_task) instead
+/// only counts task starts, since `on_enter` fires once at the first poll
+/// and never again — it will not tell you how many times a task completes.
+/// The count saturates rather than wrapping.
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    /// Create a new [`Counter`], starting at zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    /// Number of times the span has been entered so far.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl Instrument for Counter {
+    #[inline]
+    fn on_enter(&mut self) {
+        self.count = self.count.saturating_add(1);
+    }
+
+    #[inline]
+    fn on_exit(&mut self) {}
+}
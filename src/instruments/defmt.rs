@@ -0,0 +1,41 @@
+//! [`Instrument`] implementation which emits structured enter/exit events
+//! over [`defmt`].
+//!
+//! This is useful for embassy/RTIC users who already route their
+//! diagnostics over `defmt` and would rather not dedicate a spare GPIO pin
+//! to tracing a [`Future`](core::future::Future)'s spans.
+
+use crate::Instrument;
+
+/// [`Instrument`] implementation which logs enter/exit events over `defmt`.
+///
+/// Each span is tagged with a static name, so the host-side `defmt` timeline
+/// can correlate the enter/exit events of a traced future's task and poll
+/// spans without any spare pins. Pairing two differently-named [`Defmt`]
+/// instruments works with
+/// [`trace_task_and_poll`](crate::TraceFuture::trace_task_and_poll) just like
+/// any other [`Instrument`].
+pub struct Defmt {
+    name: &'static str,
+}
+
+impl Defmt {
+    /// Create a new [`Defmt`] instrument which logs under the given span
+    /// name.
+    #[inline]
+    pub fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+}
+
+impl Instrument for Defmt {
+    #[inline]
+    fn on_enter(&mut self) {
+        defmt::trace!("{}: enter", self.name);
+    }
+
+    #[inline]
+    fn on_exit(&mut self) {
+        defmt::trace!("{}: exit", self.name);
+    }
+}
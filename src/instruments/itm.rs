@@ -0,0 +1,47 @@
+//! [`Instrument`] implementation for ARM Cortex-M ITM/SWO stimulus ports.
+
+use cortex_m::peripheral::itm::Stim;
+
+use crate::Instrument;
+
+/// Marks an `on_enter` event. Written to the high bit of the marker byte.
+const ENTER_MARKER: u8 = 0x80;
+
+/// [`Instrument`] implementation which writes a marker byte to an ITM
+/// stimulus port on `on_enter` and `on_exit`.
+///
+/// This gives non-intrusive, timestamped hardware tracing over SWO that
+/// scales past the number of free GPIO pins. A user-supplied span id is
+/// encoded in the low 7 bits of the marker byte so multiple spans can be
+/// demultiplexed from a single stimulus port on the host; the high bit
+/// distinguishes an enter event (set) from an exit event (clear).
+pub struct Itm<'a> {
+    stim: &'a mut Stim,
+    span_id: u8,
+}
+
+impl<'a> Itm<'a> {
+    /// Create a new [`Itm`] instrument over the given stimulus port,
+    /// tagging every marker it writes with `span_id`.
+    ///
+    /// Only the low 7 bits of `span_id` are used.
+    #[inline]
+    pub fn new(stim: &'a mut Stim, span_id: u8) -> Self {
+        Self {
+            stim,
+            span_id: span_id & !ENTER_MARKER,
+        }
+    }
+}
+
+impl<'a> Instrument for Itm<'a> {
+    #[inline]
+    fn on_enter(&mut self) {
+        self.stim.write_u8(ENTER_MARKER | self.span_id);
+    }
+
+    #[inline]
+    fn on_exit(&mut self) {
+        self.stim.write_u8(self.span_id);
+    }
+}
@@ -0,0 +1,23 @@
+//! [`Instrument`](crate::Instrument) implementations for common targets.
+
+pub mod gpio;
+pub use gpio::*;
+
+pub mod timing;
+pub use timing::*;
+
+pub mod chain;
+pub use chain::*;
+
+pub mod counter;
+pub use counter::*;
+
+#[cfg(feature = "defmt")]
+pub mod defmt;
+#[cfg(feature = "defmt")]
+pub use self::defmt::*;
+
+#[cfg(feature = "itm")]
+pub mod itm;
+#[cfg(feature = "itm")]
+pub use itm::*;
@@ -0,0 +1,91 @@
+//! [`Instrument`] implementation which accumulates poll timing statistics
+//! from a monotonic cycle counter.
+
+use crate::Instrument;
+
+/// A monotonic, free-running counter used to time spans.
+///
+/// Implement this over a Cortex-M `DWT::cycle_count`, a SysTick-derived
+/// counter, or any `embedded-hal` timer that exposes a running count. The
+/// counter is allowed to wrap; [`Timing`] only ever computes the delta
+/// between two readings with wrapping subtraction.
+pub trait Clock {
+    /// Return the current value of the counter.
+    fn now(&self) -> u32;
+}
+
+/// [`Instrument`] implementation which measures the elapsed time between
+/// `on_enter` and `on_exit` using a [`Clock`], and folds it into running
+/// statistics.
+///
+/// This turns the crate into a lightweight self-profiler: pair it with
+/// [`trace_poll`](crate::TraceFuture::trace_poll) to quantify the actual CPU
+/// time spent polling a future, with no external instrument required.
+///
+/// A single span must not take longer than one full period of the
+/// underlying counter, or its duration cannot be distinguished from a
+/// wraparound and the computed delta will be wrong.
+pub struct Timing<C: Clock> {
+    clock: C,
+    entered_at: Option<u32>,
+    total_cycles: u32,
+    count: u32,
+    max_cycles: u32,
+}
+
+impl<C: Clock> Timing<C> {
+    /// Create a new [`Timing`] instrument over the given [`Clock`].
+    #[inline]
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            entered_at: None,
+            total_cycles: 0,
+            count: 0,
+            max_cycles: 0,
+        }
+    }
+
+    /// Return the underlying [`Clock`].
+    #[inline]
+    pub fn free(self) -> C {
+        self.clock
+    }
+
+    /// Total number of cycles accumulated across every completed span.
+    #[inline]
+    pub fn total_cycles(&self) -> u32 {
+        self.total_cycles
+    }
+
+    /// Number of spans completed so far (ie. the number of `on_enter` /
+    /// `on_exit` pairs).
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Duration, in cycles, of the longest single span seen so far.
+    #[inline]
+    pub fn max_cycles(&self) -> u32 {
+        self.max_cycles
+    }
+}
+
+impl<C: Clock> Instrument for Timing<C> {
+    #[inline]
+    fn on_enter(&mut self) {
+        self.entered_at = Some(self.clock.now());
+    }
+
+    #[inline]
+    fn on_exit(&mut self) {
+        if let Some(entered_at) = self.entered_at.take() {
+            let elapsed = self.clock.now().wrapping_sub(entered_at);
+
+            self.total_cycles = self.total_cycles.wrapping_add(elapsed);
+            self.count = self.count.wrapping_add(1);
+            self.max_cycles = self.max_cycles.max(elapsed);
+        }
+    }
+}
@@ -77,6 +77,11 @@ use core::{future::Future, pin::Pin, task::Poll};
 pub mod instruments;
 pub use instruments::*;
 
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "futures")]
+pub use stream::*;
+
 /// Extension to [`Future`] with tracing utilities.
 ///
 /// Each method takes one or more [`Instrument`]
@@ -99,6 +104,7 @@ where
             fut: self,
             instrument,
             polled_once: false,
+            entered: false,
         }
     }
 
@@ -135,6 +141,7 @@ where
             task_instrument,
             poll_instrument,
             polled_once: false,
+            entered: false,
         }
     }
 }
@@ -153,6 +160,33 @@ pub trait Instrument {
     fn on_exit(&mut self);
 }
 
+macro_rules! impl_instrument_for_tuple {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: Instrument),+> Instrument for ($($T,)+) {
+            fn on_enter(&mut self) {
+                $(self.$idx.on_enter();)+
+            }
+
+            // Exit in reverse order, so nesting stays symmetric: the
+            // instrument entered last is exited first.
+            fn on_exit(&mut self) {
+                impl_instrument_for_tuple!(@exit self; $($idx),+);
+            }
+        }
+    };
+    (@exit $self:ident; $idx:tt) => {
+        $self.$idx.on_exit();
+    };
+    (@exit $self:ident; $idx:tt, $($rest:tt),+) => {
+        impl_instrument_for_tuple!(@exit $self; $($rest),+);
+        $self.$idx.on_exit();
+    };
+}
+
+impl_instrument_for_tuple!(0: I0, 1: I1);
+impl_instrument_for_tuple!(0: I0, 1: I1, 2: I2);
+impl_instrument_for_tuple!(0: I0, 1: I1, 2: I2, 3: I3);
+
 pin_project_lite::pin_project! {
     #[must_use = "futures do nothing unless you `.await` or poll them"]
     #[doc(hidden)]
@@ -164,7 +198,26 @@ pin_project_lite::pin_project! {
         #[pin]
         fut: F,
         instrument: &'a mut I,
-        polled_once: bool
+        polled_once: bool,
+        entered: bool
+    }
+
+    impl<'a, F, I> PinnedDrop for TraceTaskFuture<'a, F, I>
+    where
+        F: Future,
+        I: Instrument,
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+
+            // The future was dropped (eg. cancelled) before reaching
+            // `Poll::Ready`. Deassert the span so a cancelled future doesn't
+            // leave its instrument stuck entered.
+            if *this.entered {
+                this.instrument.on_exit();
+                *this.entered = false;
+            }
+        }
     }
 }
 
@@ -183,6 +236,7 @@ where
 
         if !*this.polled_once {
             this.instrument.on_enter();
+            *this.entered = true;
         }
         *this.polled_once = true;
 
@@ -190,6 +244,7 @@ where
         match poll_result {
             Poll::Ready(c) => {
                 this.instrument.on_exit();
+                *this.entered = false;
                 Poll::Ready(c)
             }
             Poll::Pending => Poll::Pending,
@@ -249,6 +304,28 @@ pin_project_lite::pin_project! {
         task_instrument: &'a mut T,
         poll_instrument: &'a mut P,
         polled_once: bool,
+        entered: bool,
+    }
+
+    impl<'a, F, T, P> PinnedDrop for TraceTaskAndPollFuture<'a, F, T, P>
+    where
+        F: Future,
+        T: Instrument,
+        P: Instrument,
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+
+            // The future was dropped (eg. cancelled) before reaching
+            // `Poll::Ready`. Deassert the task span so a cancelled future
+            // doesn't leave its instrument stuck entered. The poll span is
+            // never left entered across a poll boundary, so it needs no
+            // handling here.
+            if *this.entered {
+                this.task_instrument.on_exit();
+                *this.entered = false;
+            }
+        }
     }
 }
 
@@ -268,6 +345,7 @@ where
 
         if !*this.polled_once {
             this.task_instrument.on_enter();
+            *this.entered = true;
         }
         *this.polled_once = true;
 
@@ -278,6 +356,7 @@ where
         match poll_result {
             Poll::Ready(c) => {
                 this.task_instrument.on_exit();
+                *this.entered = false;
                 Poll::Ready(c)
             }
             Poll::Pending => Poll::Pending,
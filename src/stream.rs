@@ -0,0 +1,145 @@
+//! [`Stream`] tracing, mirroring [`TraceFuture`](crate::TraceFuture) for streams.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::Instrument;
+
+/// Extension to [`Stream`] with tracing utilities.
+///
+/// Mirrors [`TraceFuture`](crate::TraceFuture), but traces calls to [`poll_next`](Stream::poll_next)
+/// instead of [`poll`](core::future::Future::poll). This is useful for
+/// embassy/embedded-io async streams (sensor sample streams, packet readers)
+/// where per-item poll timing matters.
+pub trait TraceStream: Stream
+where
+    Self: Sized,
+{
+    /// Trace a [`Stream`]'s task execution.
+    ///
+    /// The underlying [`Instrument`]
+    /// calls [`on_enter`](Instrument::on_enter) when the stream is first
+    /// polled, and calls [`on_exit`](Instrument::on_exit) when it terminates
+    /// (returns [`Poll::Ready`] with `None`). This is useful for analyzing
+    /// the total time it takes for your stream to run out of items.
+    fn trace_task<I: Instrument>(self, instrument: &mut I) -> TraceTaskStream<'_, Self, I> {
+        TraceTaskStream {
+            stream: self,
+            instrument,
+            polled_once: false,
+            entered: false,
+        }
+    }
+
+    /// Trace a [`Stream`] poll execution.
+    ///
+    /// The underlying [`Instrument`]
+    /// calls [`on_enter`](Instrument::on_enter) every time prior to the
+    /// underlying stream being polled, and calls
+    /// [`on_exit`](Instrument::on_exit) right after the
+    /// [`poll_next`](Stream::poll_next) call completes, regardless of the
+    /// yielded item. This is useful for analyzing the time it takes to poll
+    /// your stream for a single item (ie, actual CPU time used).
+    fn trace_poll<I: Instrument>(self, instrument: &mut I) -> TracePollStream<'_, Self, I> {
+        TracePollStream {
+            stream: self,
+            instrument,
+        }
+    }
+}
+
+impl<S: Stream> TraceStream for S {}
+
+pin_project_lite::pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[doc(hidden)]
+    pub struct TraceTaskStream<'a, S, I>
+    where
+        S: Stream,
+        I: Instrument,
+    {
+        #[pin]
+        stream: S,
+        instrument: &'a mut I,
+        polled_once: bool,
+        entered: bool,
+    }
+
+    impl<'a, S, I> PinnedDrop for TraceTaskStream<'a, S, I>
+    where
+        S: Stream,
+        I: Instrument,
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+
+            // The stream was dropped before terminating. Deassert the span
+            // so a cancelled stream doesn't leave its instrument stuck
+            // entered.
+            if *this.entered {
+                this.instrument.on_exit();
+                *this.entered = false;
+            }
+        }
+    }
+}
+
+impl<'p, S, I> Stream for TraceTaskStream<'p, S, I>
+where
+    S: Stream,
+    I: Instrument,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if !*this.polled_once {
+            this.instrument.on_enter();
+            *this.entered = true;
+        }
+        *this.polled_once = true;
+
+        let poll_result = this.stream.poll_next(cx);
+        if let Poll::Ready(None) = poll_result {
+            this.instrument.on_exit();
+            *this.entered = false;
+        }
+
+        poll_result
+    }
+}
+
+pin_project_lite::pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[doc(hidden)]
+    pub struct TracePollStream<'a, S, I>
+    where
+        S: Stream,
+        I: Instrument,
+    {
+        #[pin]
+        stream: S,
+        instrument: &'a mut I,
+    }
+}
+
+impl<'p, S, I> Stream for TracePollStream<'p, S, I>
+where
+    S: Stream,
+    I: Instrument,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        this.instrument.on_enter();
+        let poll_result = this.stream.poll_next(cx);
+        this.instrument.on_exit();
+
+        poll_result
+    }
+}